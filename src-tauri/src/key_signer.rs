@@ -5,13 +5,36 @@
 
 use std::io::{BufRead, BufReader, Write};
 use std::path::PathBuf;
-use tauri::command;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{Arc, Mutex};
+use shared_child::SharedChild;
+use tauri::{command, Emitter, Manager};
 
 #[cfg(unix)]
 use std::os::unix::net::UnixStream;
 
+/// Tracks the NoorSigner daemon process we spawned directly (background launch),
+/// so we can query/kill it precisely instead of guessing via pkill/taskkill.
+#[derive(Default)]
+pub struct DaemonState {
+    pub child: Mutex<Option<Arc<SharedChild>>>,
+    /// Set just before we deliberately kill the tracked child, so the watcher
+    /// thread can tell a requested stop apart from an actual crash.
+    pub kill_requested: AtomicBool,
+}
+
+/// Events emitted as the tracked daemon's lifecycle changes
+#[derive(serde::Serialize, Clone, Debug)]
+#[serde(tag = "state", content = "detail")]
+pub enum DaemonLifecycleEvent {
+    Running,
+    Exited(i32),
+    Crashed(i32),
+    InternalError(String),
+}
+
 /// Get the base path for NoorNote data (~/.noornote/)
-fn get_noornote_base_path() -> Result<PathBuf, String> {
+pub(crate) fn get_noornote_base_path() -> Result<PathBuf, String> {
     #[cfg(unix)]
     {
         let home = std::env::var("HOME")
@@ -27,7 +50,7 @@ fn get_noornote_base_path() -> Result<PathBuf, String> {
 }
 
 /// Get socket path - under ~/.noorsigner/ (NoorSigner's own directory)
-fn get_socket_path() -> Result<PathBuf, String> {
+pub(crate) fn get_socket_path() -> Result<PathBuf, String> {
     #[cfg(unix)]
     {
         let home = std::env::var("HOME")
@@ -41,9 +64,25 @@ fn get_socket_path() -> Result<PathBuf, String> {
     }
 }
 
+/// Get the Trust Mode session file path, alongside the signer socket/pipe
+pub(crate) fn get_trust_session_path() -> Result<PathBuf, String> {
+    #[cfg(unix)]
+    {
+        let home = std::env::var("HOME")
+            .map_err(|_| "Failed to get HOME directory".to_string())?;
+        Ok(PathBuf::from(home).join(".noorsigner").join("trust_session"))
+    }
+    #[cfg(windows)]
+    {
+        let home = std::env::var("USERPROFILE")
+            .map_err(|_| "Failed to get USERPROFILE directory".to_string())?;
+        Ok(PathBuf::from(home).join(".noorsigner").join("trust_session"))
+    }
+}
+
 /// Get NoorSigner binary path - always ~/.noornote/bin/noorsigner
 /// Same path for dev and prod - no more dev/prod distinction
-fn get_noorsigner_path() -> Result<PathBuf, String> {
+pub(crate) fn get_noorsigner_path() -> Result<PathBuf, String> {
     #[cfg(unix)]
     {
         Ok(get_noornote_base_path()?.join("bin").join("noorsigner"))
@@ -54,6 +93,94 @@ fn get_noorsigner_path() -> Result<PathBuf, String> {
     }
 }
 
+/// Read a single `key=value` entry from `~/.noornote/config`, if present.
+/// Used for user-overridable preferences that don't warrant a full settings UI yet.
+fn read_config_value(key: &str) -> Option<String> {
+    let config_path = get_noornote_base_path().ok()?.join("config");
+    let content = std::fs::read_to_string(&config_path).ok()?;
+
+    for line in content.lines() {
+        let line = line.trim();
+        if line.is_empty() || line.starts_with('#') {
+            continue;
+        }
+        if let Some((k, v)) = line.split_once('=') {
+            if k.trim() == key {
+                return Some(v.trim().to_string());
+            }
+        }
+    }
+    None
+}
+
+/// The user's preferred terminal emulator, from `NOORNOTE_TERMINAL` or the
+/// `terminal` key in `~/.noornote/config`. Tried before the built-in discovery list.
+fn preferred_terminal() -> Option<String> {
+    std::env::var("NOORNOTE_TERMINAL")
+        .ok()
+        .or_else(|| read_config_value("terminal"))
+}
+
+/// Known Linux terminal emulators and how each one is told to run a command.
+/// `"--"` means the command is passed as separate argv entries after it;
+/// anything else is a flag that takes the whole command line as one string arg.
+#[cfg(target_os = "linux")]
+const LINUX_TERMINALS: &[(&str, &str)] = &[
+    ("alacritty", "-e"),
+    ("kitty", "-e"),
+    ("wezterm", "--"),
+    ("foot", "-e"),
+    ("gnome-terminal", "--"),
+    ("tilix", "-e"),
+    ("xfce4-terminal", "-e"),
+    ("konsole", "-e"),
+    ("xterm", "-e"),
+];
+
+/// A resolved terminal to run NoorSigner's password prompt in on Windows.
+#[cfg(target_os = "windows")]
+struct TermConfig {
+    exec: PathBuf,
+    args: Vec<std::ffi::OsString>,
+}
+
+/// A user-configured terminal override (`terminal_exec` / `terminal_args` in
+/// `~/.noornote/config`), tried before the built-in discovery order.
+#[cfg(target_os = "windows")]
+fn configured_term_config() -> Option<TermConfig> {
+    let exec = read_config_value("terminal_exec")?;
+    let args = read_config_value("terminal_args")
+        .map(|raw| raw.split(',').map(|a| std::ffi::OsString::from(a.trim())).collect())
+        .unwrap_or_default();
+    Some(TermConfig { exec: PathBuf::from(exec), args })
+}
+
+/// Probe for a terminal in priority order: Windows Terminal, then PowerShell 7
+/// under conhost, then the built-in PowerShell. Caches nothing - `which` resolution
+/// is cheap and this only runs on launch.
+#[cfg(target_os = "windows")]
+fn default_term_config() -> Option<TermConfig> {
+    if let Ok(wt) = which::which("wt.exe").or_else(|_| which::which("wt")) {
+        return Some(TermConfig { exec: wt, args: vec!["-w".into(), "0".into(), "nt".into()] });
+    }
+
+    if let Ok(pwsh) = which::which("pwsh.exe").or_else(|_| which::which("pwsh")) {
+        if let Ok(conhost) = which::which("conhost.exe").or_else(|_| which::which("conhost")) {
+            return Some(TermConfig {
+                exec: conhost,
+                args: vec![pwsh.into_os_string(), "-NoExit".into(), "-Command".into()],
+            });
+        }
+        return Some(TermConfig { exec: pwsh, args: vec!["-NoExit".into(), "-Command".into()] });
+    }
+
+    if let Ok(powershell) = which::which("powershell.exe").or_else(|_| which::which("powershell")) {
+        return Some(TermConfig { exec: powershell, args: vec!["-NoExit".into(), "-Command".into()] });
+    }
+
+    None
+}
+
 /// Get the sidecar binary path from the app bundle
 /// This is where Tauri places the bundled NoorSigner binary
 fn get_sidecar_source_path() -> Result<PathBuf, String> {
@@ -125,18 +252,19 @@ pub async fn ensure_noorsigner_installed() -> Result<String, String> {
     if !target_dir.exists() {
         fs::create_dir_all(target_dir)
             .map_err(|e| format!("Failed to create directory {:?}: {}", target_dir, e))?;
-        println!("Created directory: {:?}", target_dir);
+        log::info!("Created directory: {:?}", target_dir);
     }
 
     // Check if NoorSigner already exists
     if target_path.exists() {
-        println!("NoorSigner already installed at: {:?}", target_path);
+        log::info!("NoorSigner already installed at: {:?}", target_path);
+        crate::signer_integrity::verify_binary(&target_path)?;
         return Ok(target_path.display().to_string());
     }
 
     // Find sidecar in bundle
     let source_path = get_sidecar_source_path()?;
-    println!("Found NoorSigner sidecar at: {:?}", source_path);
+    log::info!("Found NoorSigner sidecar at: {:?}", source_path);
 
     // Copy to target location
     fs::copy(&source_path, &target_path)
@@ -154,13 +282,125 @@ pub async fn ensure_noorsigner_installed() -> Result<String, String> {
             .map_err(|e| format!("Failed to set executable permission: {}", e))?;
     }
 
-    println!("NoorSigner installed to: {:?}", target_path);
+    // Refuse to hand back a binary that doesn't match the signed manifest
+    if let Err(e) = crate::signer_integrity::verify_binary(&target_path) {
+        fs::remove_file(&target_path).ok();
+        return Err(e);
+    }
+
+    log::info!("NoorSigner installed to: {:?}", target_path);
     Ok(target_path.display().to_string())
 }
 
-/// Send JSON-RPC request to KeySigner daemon via Unix socket
+/// Which transport `key_signer_request` uses to reach the signing backend.
+/// Defaults to the local NoorSigner daemon over its Unix socket/named pipe;
+/// can be redirected to an external command for hardware tokens or HSMs that
+/// ship their own signing tool instead of speaking the daemon's protocol.
+enum SignerBackend {
+    NamedPipe,
+    ExternalCommand { program: String, args: Vec<String> },
+}
+
+/// Read the configured signer backend from `~/.noornote/config`.
+/// `signer_backend=external` switches `key_signer_request` to invoke
+/// `signer_command` (args from the space-separated `signer_command_args`)
+/// instead of talking to the NoorSigner daemon.
+fn configured_signer_backend() -> SignerBackend {
+    match read_config_value("signer_backend").as_deref() {
+        Some("external") => {
+            let program = read_config_value("signer_command").unwrap_or_default();
+            let args = read_config_value("signer_command_args")
+                .map(|s| s.split_whitespace().map(|a| a.to_string()).collect())
+                .unwrap_or_default();
+            SignerBackend::ExternalCommand { program, args }
+        }
+        _ => SignerBackend::NamedPipe,
+    }
+}
+
+/// Send JSON-RPC request to the configured signing backend
 #[command]
 pub async fn key_signer_request(request: String) -> Result<String, String> {
+    match configured_signer_backend() {
+        SignerBackend::NamedPipe => named_pipe_signer_request(request).await,
+        SignerBackend::ExternalCommand { program, args } => {
+            if program.is_empty() {
+                return Err(
+                    "signer_backend=external requires signer_command to be set in ~/.noornote/config".to_string(),
+                );
+            }
+            external_signer_request(program, args, request).await
+        }
+    }
+}
+
+/// Invoke an external signing command instead of the NoorSigner daemon. The
+/// JSON-RPC request is written to the child's stdin and its response is read
+/// from stdout; a literal `%1` in `signer_command_args` is replaced with the
+/// request for tools that expect it as an argument rather than on stdin.
+async fn external_signer_request(program: String, args: Vec<String>, request: String) -> Result<String, String> {
+    use std::process::Stdio;
+    use tokio::io::AsyncWriteExt;
+    use tokio::process::Command;
+    use tokio::time::{timeout, Duration};
+
+    let expanded_args: Vec<String> = args
+        .iter()
+        .map(|a| if a == "%1" { request.clone() } else { a.clone() })
+        .collect();
+
+    let mut command = Command::new(&program);
+    command
+        .args(&expanded_args)
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped());
+
+    #[cfg(windows)]
+    {
+        use std::os::windows::process::CommandExt;
+        const CREATE_NO_WINDOW: u32 = 0x08000000;
+        command.creation_flags(CREATE_NO_WINDOW);
+    }
+
+    let mut child = command
+        .spawn()
+        .map_err(|e| format!("Failed to launch external signer '{}': {}", program, e))?;
+
+    {
+        let mut stdin = child
+            .stdin
+            .take()
+            .ok_or_else(|| "Failed to open external signer stdin".to_string())?;
+        stdin
+            .write_all(request.as_bytes())
+            .await
+            .map_err(|e| format!("Failed to write request to external signer: {}", e))?;
+        stdin
+            .write_all(b"\n")
+            .await
+            .map_err(|e| format!("Failed to write request to external signer: {}", e))?;
+    }
+
+    let output = timeout(Duration::from_secs(10), child.wait_with_output())
+        .await
+        .map_err(|_| "External signer timed out - is it waiting for input?".to_string())?
+        .map_err(|e| format!("Failed to read external signer output: {}", e))?;
+
+    if !output.status.success() {
+        return Err(format!(
+            "External signer '{}' exited with {}: {}",
+            program,
+            output.status,
+            String::from_utf8_lossy(&output.stderr).trim()
+        ));
+    }
+
+    Ok(String::from_utf8_lossy(&output.stdout).trim_end().to_string())
+}
+
+/// Send JSON-RPC request to KeySigner daemon via Unix socket/named pipe
+async fn named_pipe_signer_request(request: String) -> Result<String, String> {
     #[cfg(unix)]
     {
         use std::time::Duration;
@@ -207,56 +447,374 @@ pub async fn key_signer_request(request: String) -> Result<String, String> {
 
     #[cfg(windows)]
     {
-        // Windows Named Pipes implementation
-        // TODO: Implement Windows named pipe support
-        Err("Windows named pipes not yet implemented".to_string())
+        windows_pipe::send_request(request).await
+    }
+}
+
+/// A long-lived, reconnecting named-pipe connection shared across concurrent
+/// requests. The writer half is guarded by an async mutex; the reader half is
+/// owned by a single background task that dispatches each line to the caller
+/// waiting on its JSON-RPC `id`, so concurrent `key_signer_request` calls no
+/// longer race each other for the next line on the pipe.
+#[cfg(windows)]
+mod windows_pipe {
+    use serde_json::Value;
+    use std::collections::HashMap;
+    use std::sync::{Mutex as StdMutex, OnceLock};
+    use tokio::io::{AsyncBufReadExt, AsyncWriteExt, BufReader, ReadHalf, WriteHalf};
+    use tokio::net::windows::named_pipe::{ClientOptions, NamedPipeClient};
+    use tokio::sync::{oneshot, Mutex as AsyncMutex};
+    use tokio::time::{Duration, Instant};
+
+    // ERROR_PIPE_BUSY: another client is connected, the daemon just hasn't
+    // accepted us yet. Retry for a few seconds instead of failing fast.
+    const ERROR_PIPE_BUSY: i32 = 231;
+
+    type PendingMap = HashMap<u64, oneshot::Sender<String>>;
+
+    fn pending() -> &'static StdMutex<PendingMap> {
+        static PENDING: OnceLock<StdMutex<PendingMap>> = OnceLock::new();
+        PENDING.get_or_init(|| StdMutex::new(HashMap::new()))
+    }
+
+    fn writer_slot() -> &'static AsyncMutex<Option<WriteHalf<NamedPipeClient>>> {
+        static WRITER: OnceLock<AsyncMutex<Option<WriteHalf<NamedPipeClient>>>> = OnceLock::new();
+        WRITER.get_or_init(|| AsyncMutex::new(None))
+    }
+
+    async fn connect_with_retry() -> Result<NamedPipeClient, String> {
+        let pipe_path = super::get_socket_path()?;
+        let pipe_path = pipe_path.to_str().ok_or_else(|| "Invalid named pipe path".to_string())?;
+
+        let deadline = Instant::now() + Duration::from_secs(10);
+        loop {
+            match ClientOptions::new().open(pipe_path) {
+                Ok(client) => return Ok(client),
+                Err(e) if e.raw_os_error() == Some(ERROR_PIPE_BUSY) && Instant::now() < deadline => {
+                    tokio::time::sleep(Duration::from_millis(50)).await;
+                }
+                Err(e) => {
+                    return Err(format!(
+                        "Failed to connect to KeySigner daemon: {}. Is the daemon running?",
+                        e
+                    ));
+                }
+            }
+        }
+    }
+
+    fn extract_id(line: &str) -> Option<u64> {
+        serde_json::from_str::<Value>(line).ok()?.get("id")?.as_u64()
+    }
+
+    async fn reader_task(read_half: ReadHalf<NamedPipeClient>) {
+        let mut reader = BufReader::new(read_half);
+        loop {
+            let mut line = String::new();
+            match reader.read_line(&mut line).await {
+                Ok(0) => {
+                    log::error!("NoorSigner pipe closed - next request will reconnect");
+                    break;
+                }
+                Ok(_) => {
+                    if let Some(id) = extract_id(&line) {
+                        if let Some(sender) = pending().lock().unwrap().remove(&id) {
+                            let _ = sender.send(line.trim_end().to_string());
+                        }
+                    }
+                }
+                Err(e) => {
+                    log::error!("Failed reading from NoorSigner pipe: {}", e);
+                    break;
+                }
+            }
+        }
+        *writer_slot().lock().await = None;
+    }
+
+    async fn ensure_connected() -> Result<(), String> {
+        let mut slot = writer_slot().lock().await;
+        if slot.is_some() {
+            return Ok(());
+        }
+
+        let client = connect_with_retry().await?;
+        let (read_half, write_half) = tokio::io::split(client);
+        *slot = Some(write_half);
+
+        tokio::spawn(reader_task(read_half));
+        Ok(())
+    }
+
+    /// Send one JSON-RPC request and wait for the response matching its `id`,
+    /// with a per-request timeout since a hung daemon must not wedge the caller.
+    pub async fn send_request(request: String) -> Result<String, String> {
+        let id = serde_json::from_str::<Value>(&request)
+            .ok()
+            .and_then(|v| v.get("id").and_then(|id| id.as_u64()))
+            .ok_or_else(|| "Request is missing a JSON-RPC \"id\" field".to_string())?;
+
+        ensure_connected().await?;
+
+        let (tx, rx) = oneshot::channel();
+        pending().lock().unwrap().insert(id, tx);
+
+        let send_result: Result<(), String> = async {
+            let mut slot = writer_slot().lock().await;
+            let writer = slot
+                .as_mut()
+                .ok_or_else(|| "NoorSigner connection is not available".to_string())?;
+            writer
+                .write_all(request.as_bytes())
+                .await
+                .map_err(|e| format!("Failed to send request: {}", e))?;
+            writer
+                .write_all(b"\n")
+                .await
+                .map_err(|e| format!("Failed to send newline: {}", e))
+        }
+        .await;
+
+        if let Err(e) = send_result {
+            pending().lock().unwrap().remove(&id);
+            return Err(e);
+        }
+
+        match tokio::time::timeout(Duration::from_secs(10), rx).await {
+            Ok(Ok(response)) => Ok(response),
+            Ok(Err(_)) => Err("Connection to NoorSigner daemon was lost while waiting for a response".to_string()),
+            Err(_) => {
+                pending().lock().unwrap().remove(&id);
+                Err("Request timed out - daemon may have crashed or is unresponsive".to_string())
+            }
+        }
+    }
+
+    /// Active readiness probe: ping the daemon and wait for a pong, rather
+    /// than just checking whether the pipe can be opened. A bare open
+    /// succeeds the moment the daemon creates the pipe, even if it's still
+    /// initializing (or wedged) and not actually able to answer requests yet.
+    pub async fn ping() -> bool {
+        let request = serde_json::json!({"jsonrpc": "2.0", "method": "ping", "id": 0}).to_string();
+        match tokio::time::timeout(Duration::from_millis(500), send_request(request)).await {
+            Ok(Ok(response)) => response.contains("pong"),
+            _ => false,
+        }
+    }
+}
+
+/// Decode a lowercase hex string into bytes. Returns `Err(())` on any malformed input.
+/// Shared with `signer_integrity` so the two crypto-adjacent modules don't each
+/// carry their own copy.
+pub(crate) fn hex_decode(s: &str) -> Result<Vec<u8>, ()> {
+    if s.len() % 2 != 0 {
+        return Err(());
+    }
+    (0..s.len())
+        .step_by(2)
+        .map(|i| u8::from_str_radix(&s[i..i + 2], 16).map_err(|_| ()))
+        .collect()
+}
+
+/// Encode bytes as a lowercase hex string.
+pub(crate) fn hex_encode(bytes: &[u8]) -> String {
+    bytes.iter().map(|b| format!("{:02x}", b)).collect()
+}
+
+/// A secret tied to this machine, used as Argon2id input material for the
+/// Trust Mode session key. Not a substitute for a user passphrase, but
+/// enough to stop a copied trust_session file from working on another device.
+fn device_bound_secret() -> Result<String, String> {
+    #[cfg(unix)]
+    {
+        std::fs::read_to_string("/etc/machine-id")
+            .or_else(|_| std::fs::read_to_string("/var/lib/dbus/machine-id"))
+            .map(|s| s.trim().to_string())
+            .map_err(|e| format!("Failed to read device identifier: {}", e))
+    }
+    #[cfg(windows)]
+    {
+        std::env::var("COMPUTERNAME").map_err(|_| "Failed to read device identifier".to_string())
+    }
+}
+
+/// Derive the 32-byte trust-session key from the device-bound secret and the
+/// salt stored alongside the trust_session file, using Argon2id default params.
+fn derive_trust_session_key(salt: &[u8]) -> Result<[u8; 32], String> {
+    use argon2::Argon2;
+
+    let secret = device_bound_secret()?;
+    let mut key = [0u8; 32];
+    Argon2::default()
+        .hash_password_into(secret.as_bytes(), salt, &mut key)
+        .map_err(|e| format!("Failed to derive trust session key: {}", e))?;
+    Ok(key)
+}
+
+/// Verify a trust_session file's content against its salt, given the current
+/// time. Pure (no I/O), so the AEAD verification logic can be exercised
+/// directly in tests instead of only through the filesystem-backed command.
+///
+/// Expected `content` format: `token:expires_unix:created_unix:nonce_hex:tag_hex`.
+/// `tag_hex` is an XChaCha20-Poly1305 AEAD tag computed over empty plaintext with
+/// the canonical `token|expires_unix|created_unix` string as associated data, so
+/// tampering with any of those fields invalidates the session even though they're
+/// stored as plaintext.
+fn verify_trust_session(content: &str, salt: &[u8], now: i64) -> bool {
+    use chacha20poly1305::aead::{Aead, Payload};
+    use chacha20poly1305::{KeyInit, XChaCha20Poly1305, XNonce};
+
+    let parts: Vec<&str> = content.trim().split(':').collect();
+    if parts.len() != 5 {
+        return false;
+    }
+    let (token, expires_str, created_str, nonce_hex, tag_hex) =
+        (parts[0], parts[1], parts[2], parts[3], parts[4]);
+
+    let Ok(expires_unix) = expires_str.parse::<i64>() else {
+        return false;
+    };
+    let Ok(created_unix) = created_str.parse::<i64>() else {
+        return false;
+    };
+
+    if now >= expires_unix {
+        return false;
+    }
+
+    let Ok(nonce_bytes) = hex_decode(nonce_hex) else {
+        return false;
+    };
+    let Ok(tag_bytes) = hex_decode(tag_hex) else {
+        return false;
+    };
+    if nonce_bytes.len() != 24 {
+        return false;
+    }
+
+    let mut key = match derive_trust_session_key(salt) {
+        Ok(key) => key,
+        Err(_) => return false,
+    };
+
+    let canonical = format!("{}|{}|{}", token, expires_unix, created_unix);
+    let nonce = XNonce::from_slice(&nonce_bytes);
+    let authentic = XChaCha20Poly1305::new_from_slice(&key)
+        .ok()
+        .map(|cipher| {
+            cipher
+                .decrypt(nonce, Payload { msg: &tag_bytes, aad: canonical.as_bytes() })
+                .is_ok()
+        })
+        .unwrap_or(false);
+
+    // Wipe the derived key from memory now that we're done with it
+    for byte in key.iter_mut() {
+        unsafe { std::ptr::write_volatile(byte, 0) };
     }
+
+    authentic
 }
 
-/// Check if Trust Mode session is valid
+/// Check if Trust Mode session is valid.
+///
+/// On-disk format: `trust_session` holds `token:expires_unix:created_unix:nonce_hex:tag_hex`,
+/// with a sibling `trust_session.salt` file holding the raw Argon2id salt. See
+/// `verify_trust_session` for the verification scheme.
 #[command]
 pub async fn check_trust_session() -> Result<bool, String> {
     use std::fs;
     use std::time::{SystemTime, UNIX_EPOCH};
 
-    let home = std::env::var("HOME")
-        .map_err(|_| "Failed to get HOME directory".to_string())?;
-    let trust_session_path = PathBuf::from(home)
-        .join(".noorsigner")
-        .join("trust_session");
+    let trust_session_path = get_trust_session_path()?;
 
-    // Check if trust session file exists
     if !trust_session_path.exists() {
         return Ok(false);
     }
 
-    // Read trust session file
-    let content = fs::read_to_string(&trust_session_path)
-        .map_err(|e| format!("Failed to read trust session: {}", e))?;
-
-    // Parse format: token:expires_unix:created_unix:encrypted_nsec_hex
-    let parts: Vec<&str> = content.split(':').collect();
-    if parts.len() != 4 {
+    let Ok(content) = fs::read_to_string(&trust_session_path) else {
         return Ok(false);
-    }
+    };
 
-    let expires_unix: i64 = parts[1]
-        .parse()
-        .map_err(|_| "Invalid expiry timestamp".to_string())?;
+    let Ok(salt) = fs::read(trust_session_path.with_extension("salt")) else {
+        return Ok(false);
+    };
 
-    // Check if still valid
     let now = SystemTime::now()
         .duration_since(UNIX_EPOCH)
         .map_err(|_| "Failed to get current time".to_string())?
         .as_secs() as i64;
 
-    Ok(now < expires_unix)
+    Ok(verify_trust_session(&content, &salt, now))
+}
+
+/// Report the status of the tracked daemon process, if any
+#[command]
+pub async fn daemon_status(state: tauri::State<'_, DaemonState>) -> Result<String, String> {
+    let guard = state.child.lock().map_err(|_| "Daemon state lock poisoned".to_string())?;
+    match guard.as_ref() {
+        None => Ok("not_tracked".to_string()),
+        Some(child) => match child.try_wait() {
+            Ok(Some(status)) => Ok(format!("exited:{}", status.code().unwrap_or(-1))),
+            Ok(None) => Ok("running".to_string()),
+            Err(e) => Err(format!("Failed to query daemon status: {}", e)),
+        },
+    }
+}
+
+/// Kill the tracked daemon process, if any
+#[command]
+pub async fn kill_daemon(state: tauri::State<'_, DaemonState>) -> Result<(), String> {
+    let guard = state.child.lock().map_err(|_| "Daemon state lock poisoned".to_string())?;
+    match guard.as_ref() {
+        Some(child) => {
+            // Set before killing so the watcher thread sees it before try_wait() wakes up
+            state.kill_requested.store(true, Ordering::SeqCst);
+            child.kill().map_err(|e| format!("Failed to kill daemon: {}", e))
+        }
+        None => Ok(()),
+    }
+}
+
+/// Block until the tracked daemon process exits, returning its exit code
+#[command]
+pub async fn wait_daemon(state: tauri::State<'_, DaemonState>) -> Result<i32, String> {
+    let child = {
+        let guard = state.child.lock().map_err(|_| "Daemon state lock poisoned".to_string())?;
+        guard.clone()
+    };
+
+    match child {
+        Some(child) => {
+            let status = tauri::async_runtime::spawn_blocking(move || child.wait())
+                .await
+                .map_err(|e| format!("Failed to join wait task: {}", e))?
+                .map_err(|e| format!("Failed to wait for daemon: {}", e))?;
+            Ok(status.code().unwrap_or(-1))
+        }
+        None => Err("No tracked daemon process".to_string()),
+    }
 }
 
 /// Cancel KeySigner launch by killing any running noorsigner daemon process
 /// This closes the terminal window where password entry is pending
 #[command]
-pub async fn cancel_key_signer_launch() -> Result<(), String> {
+pub async fn cancel_key_signer_launch(app: tauri::AppHandle) -> Result<(), String> {
+    // Prefer killing the process we're actually tracking - precise and race-free
+    let tracked_child = {
+        let state = app.state::<DaemonState>();
+        let guard = state.child.lock().map_err(|_| "Daemon state lock poisoned".to_string())?;
+        guard.clone()
+    };
+
+    if let Some(child) = tracked_child {
+        app.state::<DaemonState>().kill_requested.store(true, Ordering::SeqCst);
+        child.kill().map_err(|e| format!("Failed to kill tracked daemon: {}", e))?;
+        log::info!("Killed tracked noorsigner daemon process - terminal should close");
+        return Ok(());
+    }
+
+    // No tracked child (terminal-launched or untracked instance) - fall back
     #[cfg(unix)]
     {
         use std::process::Command;
@@ -270,11 +828,11 @@ pub async fn cancel_key_signer_launch() -> Result<(), String> {
             .map_err(|e| format!("Failed to kill noorsigner process: {}", e))?;
 
         if output.status.success() {
-            println!("Killed noorsigner daemon process - terminal should close");
+            log::info!("Killed noorsigner daemon process - terminal should close");
             Ok(())
         } else {
             // Process might not exist (user already closed terminal) - not an error
-            println!("No noorsigner daemon process found to kill");
+            log::info!("No noorsigner daemon process found to kill");
             Ok(())
         }
     }
@@ -283,29 +841,232 @@ pub async fn cancel_key_signer_launch() -> Result<(), String> {
     {
         use std::process::Command;
 
-        // Windows: taskkill noorsigner
+        // Match the exact image name we actually launch, not a wildcard guess
+        let image_name = get_noorsigner_path()?
+            .file_name()
+            .and_then(|name| name.to_str())
+            .map(|name| name.to_string())
+            .unwrap_or_else(|| "noorsigner.exe".to_string());
+
         let output = Command::new("taskkill")
             .arg("/F")
             .arg("/IM")
-            .arg("noorsigner*.exe")
+            .arg(&image_name)
             .output()
             .map_err(|e| format!("Failed to kill noorsigner process: {}", e))?;
 
         if output.status.success() {
-            println!("Killed noorsigner daemon process");
+            log::info!("Killed noorsigner daemon process");
             Ok(())
         } else {
-            println!("No noorsigner daemon process found to kill");
+            log::info!("No noorsigner daemon process found to kill");
             Ok(())
         }
     }
 }
 
+/// What `reset_signer_state` actually removed, so the UI can confirm it to the user
+#[derive(serde::Serialize)]
+pub struct ResetReport {
+    pub trust_session_removed: bool,
+    pub socket_removed: bool,
+    pub binary_removed: bool,
+    pub binary_reinstalled: bool,
+}
+
+/// Wipe all NoorNote/NoorSigner on-disk artifacts, returning the app to a
+/// first-run state. Refuses to touch anything outside the base directories
+/// computed by `get_noornote_base_path`/`get_socket_path`.
+#[command]
+pub async fn reset_signer_state(
+    app: tauri::AppHandle,
+    remove_binary: bool,
+    reinstall_binary: bool,
+) -> Result<ResetReport, String> {
+    // Stop any tracked or untracked daemon first so we don't delete files out from under it
+    let _ = kill_daemon(app.state::<DaemonState>()).await;
+    let _ = cancel_key_signer_launch(app.clone()).await;
+
+    let mut report = ResetReport {
+        trust_session_removed: false,
+        socket_removed: false,
+        binary_removed: false,
+        binary_reinstalled: false,
+    };
+
+    let trust_session_path = get_trust_session_path()?;
+    if trust_session_path.exists() {
+        fs_remove_file(&trust_session_path)?;
+        report.trust_session_removed = true;
+    }
+    // Remove the salt file written alongside it, if any - not worth its own report field
+    let _ = std::fs::remove_file(trust_session_path.with_extension("salt"));
+
+    #[cfg(unix)]
+    {
+        let socket_path = get_socket_path()?;
+        if socket_path.exists() {
+            fs_remove_file(&socket_path)?;
+            report.socket_removed = true;
+        }
+    }
+
+    if remove_binary {
+        let base_path = get_noornote_base_path()?;
+        let binary_path = get_noorsigner_path()?;
+        if !binary_path.starts_with(&base_path) {
+            return Err("Refusing to delete NoorSigner binary outside the NoorNote base directory".to_string());
+        }
+        if binary_path.exists() {
+            fs_remove_file(&binary_path)?;
+            report.binary_removed = true;
+        }
+    }
+
+    if reinstall_binary {
+        ensure_noorsigner_installed().await?;
+        report.binary_reinstalled = true;
+    }
+
+    Ok(report)
+}
+
+fn fs_remove_file(path: &std::path::Path) -> Result<(), String> {
+    std::fs::remove_file(path).map_err(|e| format!("Failed to remove {:?}: {}", path, e))
+}
+
+/// Active readiness probe for the daemon. On Windows, a named pipe can be
+/// opened the moment the daemon creates it, even if it's still initializing
+/// (or wedged) and unable to answer requests yet, so this pings the daemon
+/// and waits for a "pong". On Unix this just checks that the socket file
+/// exists rather than performing a full round trip - cheaper, and in practice
+/// the socket only appears once the daemon is already accepting connections.
+/// There's only ever one daemon pipe/socket, resolved the same way everywhere,
+/// so this takes no path argument - it isn't something a caller can override.
+async fn daemon_is_ready() -> bool {
+    #[cfg(unix)]
+    {
+        get_socket_path().map(|p| p.exists()).unwrap_or(false)
+    }
+    #[cfg(windows)]
+    {
+        windows_pipe::ping().await
+    }
+}
+
+/// Ping the NoorSigner daemon and report whether it answered in time. On
+/// Windows this is an active ping/pong handshake; on Unix the socket file's
+/// existence already implies the daemon is bound and accepting connections.
+#[command]
+pub async fn ping_key_signer() -> Result<bool, String> {
+    Ok(daemon_is_ready().await)
+}
+
+/// Everything the UI needs to show (or let the user copy) for a bug report
+#[derive(serde::Serialize)]
+pub struct SignerDiagnostics {
+    pub socket_path: String,
+    pub binary_path: String,
+    pub trust_session_path: String,
+    pub daemon_running: bool,
+    pub trust_session_valid: bool,
+    pub recent_log_tail: String,
+}
+
+/// Resolved paths, daemon/trust status, and the tail of the rotating log file,
+/// bundled for a "copy diagnostics" button so users can attach it to bug reports.
+#[command]
+pub async fn collect_signer_diagnostics() -> Result<SignerDiagnostics, String> {
+    let socket_path = get_socket_path()?;
+    let binary_path = get_noorsigner_path()?;
+    let trust_session_path = get_trust_session_path()?;
+
+    let daemon_running = daemon_is_ready().await;
+
+    let trust_session_valid = check_trust_session().await.unwrap_or(false);
+
+    let log_path = get_noornote_base_path()?.join("logs").join("noornote.log");
+    let recent_log_tail = std::fs::read_to_string(&log_path)
+        .map(|content| {
+            let lines: Vec<&str> = content.lines().collect();
+            let start = lines.len().saturating_sub(200);
+            lines[start..].join("\n")
+        })
+        .unwrap_or_default();
+
+    Ok(SignerDiagnostics {
+        socket_path: socket_path.display().to_string(),
+        binary_path: binary_path.display().to_string(),
+        trust_session_path: trust_session_path.display().to_string(),
+        daemon_running,
+        trust_session_valid,
+        recent_log_tail,
+    })
+}
+
+/// Path to the PID file we write for the background-launched daemon, so a
+/// crashed daemon doesn't leave the UI believing the signer is alive across
+/// app restarts. `DaemonState` alone can't do this - it's in-process memory
+/// only and is gone the moment NoorNote restarts.
+#[cfg(windows)]
+fn pid_file_path() -> Result<PathBuf, String> {
+    let appdata = std::env::var("APPDATA").map_err(|_| "Failed to get APPDATA directory".to_string())?;
+    Ok(PathBuf::from(appdata).join("NoorSigner").join("noorsigner.pid"))
+}
+
+#[cfg(windows)]
+fn write_pid_file(pid: u32) -> Result<(), String> {
+    let path = pid_file_path()?;
+    if let Some(parent) = path.parent() {
+        std::fs::create_dir_all(parent).map_err(|e| format!("Failed to create PID file directory: {}", e))?;
+    }
+    std::fs::write(path, pid.to_string()).map_err(|e| format!("Failed to write PID file: {}", e))
+}
+
+#[cfg(windows)]
+fn read_pid_file() -> Option<u32> {
+    std::fs::read_to_string(pid_file_path().ok()?).ok()?.trim().parse().ok()
+}
+
+#[cfg(windows)]
+fn remove_pid_file() {
+    if let Ok(path) = pid_file_path() {
+        let _ = std::fs::remove_file(path);
+    }
+}
+
+/// Ask `tasklist` whether a PID still refers to a live process
+#[cfg(windows)]
+fn pid_is_alive(pid: u32) -> bool {
+    use std::process::Command;
+    Command::new("tasklist")
+        .args(["/FI", &format!("PID eq {}", pid)])
+        .output()
+        .map(|o| String::from_utf8_lossy(&o.stdout).contains(&pid.to_string()))
+        .unwrap_or(false)
+}
+
+/// Reconcile a stale PID file left over from a previous app run: if the
+/// process it names is gone, clear the file rather than leave the UI
+/// believing a crashed daemon from a prior session is still alive.
+#[cfg(windows)]
+fn reconcile_stale_pid() {
+    if let Some(pid) = read_pid_file() {
+        if !pid_is_alive(pid) {
+            log::info!("Clearing stale noorsigner PID file (pid {} no longer running)", pid);
+            remove_pid_file();
+        }
+    }
+}
+
 /// Launch NoorSigner CLI binary
 #[command]
-pub async fn launch_key_signer(mode: String) -> Result<(), String> {
+pub async fn launch_key_signer(app: tauri::AppHandle, mode: String) -> Result<(), String> {
     use std::process::Command;
 
+    #[cfg(windows)]
+    reconcile_stale_pid();
+
     // Ensure NoorSigner is installed first
     ensure_noorsigner_installed().await?;
 
@@ -339,50 +1100,101 @@ pub async fn launch_key_signer(mode: String) -> Result<(), String> {
         _ => return Err(format!("Invalid mode: {}", mode)),
     };
 
-    println!("Launching NoorSigner: {} {}", noorsigner_path.display(), command);
+    log::info!("Launching NoorSigner: {} {}", noorsigner_path.display(), command);
 
     // Check if Trust Mode is valid AND daemon is not already running
     // Trust session is only useful if daemon is NOT running yet
     let has_trust_session = check_trust_session().await.unwrap_or(false);
 
-    // Also check if daemon is already running by checking socket existence
-    let socket_path = get_socket_path()?;
-    let daemon_already_running = socket_path.exists();
+    // Also check if daemon is already running
+    let daemon_already_running = daemon_is_ready().await;
 
-    println!("Trust session valid: {}", has_trust_session);
-    println!("Daemon already running: {}", daemon_already_running);
+    log::info!("Trust session valid: {}", has_trust_session);
+    log::info!("Daemon already running: {}", daemon_already_running);
 
     // Only use background launch if trust session exists AND daemon is not already running
     // If daemon is already running, no need to launch again
     if has_trust_session && !daemon_already_running && mode == "daemon" {
         // Trust session exists - try to run daemon in background (no terminal)
-        println!("Trust session valid + daemon not running - attempting background launch...");
+        log::info!("Trust session valid + daemon not running - attempting background launch...");
 
         #[cfg(unix)]
-        {
+        let mut daemon_command = {
             use std::os::unix::process::CommandExt;
-            Command::new(&noorsigner_path)
-                .arg(command)
+            let mut cmd = Command::new(&noorsigner_path);
+            cmd.arg(command)
                 .stdin(std::process::Stdio::null())
                 .stdout(std::process::Stdio::null())
                 .stderr(std::process::Stdio::null())
-                .process_group(0) // Create new process group
-                .spawn()
-                .map_err(|e| format!("Failed to launch NoorSigner in background: {}", e))?;
-        }
+                .process_group(0); // Create new process group
+            cmd
+        };
 
         #[cfg(windows)]
-        {
-            Command::new(&noorsigner_path)
-                .arg(command)
+        let mut daemon_command = {
+            let mut cmd = Command::new(&noorsigner_path);
+            cmd.arg(command)
                 .stdin(std::process::Stdio::null())
                 .stdout(std::process::Stdio::null())
-                .stderr(std::process::Stdio::null())
-                .spawn()
-                .map_err(|e| format!("Failed to launch NoorSigner in background: {}", e))?;
+                .stderr(std::process::Stdio::null());
+            cmd
+        };
+
+        let daemon_child = Arc::new(
+            SharedChild::spawn(&mut daemon_command)
+                .map_err(|e| format!("Failed to launch NoorSigner in background: {}", e))?,
+        );
+
+        {
+            let daemon_state = app.state::<DaemonState>();
+            daemon_state.kill_requested.store(false, Ordering::SeqCst);
+            *daemon_state.child.lock().map_err(|_| "Daemon state lock poisoned".to_string())? =
+                Some(daemon_child.clone());
         }
 
-        println!("Background daemon launched - waiting for socket to appear...");
+        #[cfg(windows)]
+        write_pid_file(daemon_child.id())?;
+
+        // Watch the daemon in the background and let the UI know when it dies,
+        // instead of silently losing the socket with no explanation.
+        let watch_child = daemon_child.clone();
+        let watch_app = app.clone();
+        std::thread::spawn(move || loop {
+            match watch_child.try_wait() {
+                Ok(Some(status)) => {
+                    #[cfg(windows)]
+                    remove_pid_file();
+
+                    // A deliberate kill_daemon/cancel_key_signer_launch never exits with
+                    // success() (it's a SIGKILL/TerminateProcess) - so we can't classify
+                    // from the exit status alone. Trust the flag set just before killing.
+                    let killed = watch_app
+                        .state::<DaemonState>()
+                        .kill_requested
+                        .swap(false, Ordering::SeqCst);
+                    let event = if killed {
+                        DaemonLifecycleEvent::Exited(status.code().unwrap_or(0))
+                    } else {
+                        DaemonLifecycleEvent::Crashed(status.code().unwrap_or(-1))
+                    };
+                    let _ = watch_app.emit("daemon-lifecycle", event);
+                    break;
+                }
+                Ok(None) => std::thread::sleep(std::time::Duration::from_millis(500)),
+                Err(e) => {
+                    #[cfg(windows)]
+                    remove_pid_file();
+                    let _ = watch_app.emit(
+                        "daemon-lifecycle",
+                        DaemonLifecycleEvent::InternalError(e.to_string()),
+                    );
+                    log::error!("Failed to poll daemon status: {}", e);
+                    break;
+                }
+            }
+        });
+
+        log::info!("Background daemon launched - waiting for socket to appear...");
 
         // Wait for socket to appear (daemon startup validation)
         // If socket doesn't appear within 3 seconds, trust session is invalid
@@ -391,26 +1203,22 @@ pub async fn launch_key_signer(mode: String) -> Result<(), String> {
         let timeout = Duration::from_secs(3);
 
         while start.elapsed() < timeout {
-            if socket_path.exists() {
-                println!("Socket appeared - daemon started successfully!");
+            if daemon_is_ready().await {
+                log::info!("Daemon is ready - started successfully!");
                 return Ok(());
             }
-            std::thread::sleep(Duration::from_millis(100));
+            tokio::time::sleep(Duration::from_millis(100)).await;
         }
 
         // Socket didn't appear - trust session is invalid or daemon failed to start
-        println!("Socket did not appear - trust session likely invalid, falling back to terminal launch");
+        log::info!("Socket did not appear - trust session likely invalid, falling back to terminal launch");
 
         // Delete invalid trust session
-        let home = std::env::var("HOME")
-            .map_err(|_| "Failed to get HOME directory".to_string())?;
-        let trust_session_path = PathBuf::from(home)
-            .join(".noorsigner")
-            .join("trust_session");
+        let trust_session_path = get_trust_session_path()?;
 
         if trust_session_path.exists() {
             let _ = std::fs::remove_file(&trust_session_path);
-            println!("Removed invalid trust session file");
+            log::info!("Removed invalid trust session file");
         }
 
         // Fall through to terminal launch (background launch failed)
@@ -418,29 +1226,46 @@ pub async fn launch_key_signer(mode: String) -> Result<(), String> {
 
     // If we reach here: no trust session, init mode, or background launch failed
     // Open terminal for user input
-    println!("Launching in terminal for user input");
+    log::info!("Launching in terminal for user input");
 
     #[cfg(target_os = "macos")]
     {
         let terminal_command = format!("{} {}", noorsigner_path.display(), command);
 
-        println!("=== DEBUG: Terminal command to execute ===");
-        println!("Binary path: {}", noorsigner_path.display());
-        println!("Command: {}", command);
-        println!("Full terminal_command: {}", terminal_command);
+        log::info!("=== DEBUG: Terminal command to execute ===");
+        log::info!("Binary path: {}", noorsigner_path.display());
+        log::info!("Command: {}", command);
+        log::info!("Full terminal_command: {}", terminal_command);
+
+        // Prefer iTerm2 when it's installed, since it's the common choice for
+        // developers on macOS; fall back to the bundled Terminal.app otherwise.
+        let use_iterm = std::path::Path::new("/Applications/iTerm.app").exists();
 
         // Launch terminal with noorsigner
-        // Use 'activate' BEFORE 'do script' to ensure Terminal.app is ready
-        // This prevents silent failures when Terminal was previously closed
-        let applescript = format!(
-            "tell application \"Terminal\"\n\
-             activate\n\
-             do script \"{}\"\n\
-             end tell",
-            terminal_command
-        );
+        // Use 'activate' BEFORE 'do script'/'write text' to ensure the app is ready
+        // This prevents silent failures when the terminal was previously closed
+        let applescript = if use_iterm {
+            format!(
+                "tell application \"iTerm\"\n\
+                 activate\n\
+                 set newWindow to (create window with default profile)\n\
+                 tell current session of newWindow\n\
+                 write text \"{}\"\n\
+                 end tell\n\
+                 end tell",
+                terminal_command
+            )
+        } else {
+            format!(
+                "tell application \"Terminal\"\n\
+                 activate\n\
+                 do script \"{}\"\n\
+                 end tell",
+                terminal_command
+            )
+        };
 
-        println!("AppleScript:\n{}", applescript);
+        log::info!("AppleScript:\n{}", applescript);
 
         let output = Command::new("osascript")
             .arg("-e")
@@ -452,24 +1277,44 @@ pub async fn launch_key_signer(mode: String) -> Result<(), String> {
         if !output.status.success() {
             let stderr = String::from_utf8_lossy(&output.stderr);
             let stdout = String::from_utf8_lossy(&output.stdout);
-            println!("osascript FAILED!");
-            println!("stderr: {}", stderr);
-            println!("stdout: {}", stdout);
+            log::error!("osascript FAILED!");
+            log::error!("stderr: {}", stderr);
+            log::error!("stdout: {}", stdout);
             return Err(format!("osascript failed: {}", stderr));
         }
 
-        println!("Terminal.app launched successfully via osascript");
+        log::info!("Terminal.app launched successfully via osascript");
     }
 
     #[cfg(target_os = "linux")]
     {
-        // Try common terminal emulators
-        let terminals = ["gnome-terminal", "konsole", "xterm"];
-        let mut launched = false;
+        // Resolve candidate terminals on PATH instead of blindly spawning each one.
+        // The user's preferred terminal (if configured and found) is tried first.
+        let mut candidates: Vec<(std::path::PathBuf, &str)> = Vec::new();
+
+        if let Some(preferred) = preferred_terminal() {
+            if let Ok(path) = which::which(&preferred) {
+                let run_arg = LINUX_TERMINALS
+                    .iter()
+                    .find(|(name, _)| *name == preferred)
+                    .map(|(_, run_arg)| *run_arg)
+                    .unwrap_or("-e");
+                candidates.push((path, run_arg));
+            } else {
+                log::info!("Preferred terminal '{}' not found on PATH, falling back to discovery", preferred);
+            }
+        }
+
+        for (name, run_arg) in LINUX_TERMINALS {
+            if let Ok(path) = which::which(name) {
+                candidates.push((path, run_arg));
+            }
+        }
 
-        for terminal in &terminals {
-            let result = if *terminal == "gnome-terminal" {
-                // gnome-terminal uses -- to separate its args from the command
+        let mut launched = false;
+        for (terminal, run_arg) in &candidates {
+            let result = if *run_arg == "--" {
+                // "--" terminals take the command as separate argv entries
                 Command::new(terminal)
                     .arg("--")
                     .arg(noorsigner_path.to_str().unwrap())
@@ -477,7 +1322,7 @@ pub async fn launch_key_signer(mode: String) -> Result<(), String> {
                     .spawn()
             } else {
                 Command::new(terminal)
-                    .arg("-e")
+                    .arg(run_arg)
                     .arg(format!("{} {}", noorsigner_path.display(), command))
                     .spawn()
             };
@@ -489,22 +1334,119 @@ pub async fn launch_key_signer(mode: String) -> Result<(), String> {
         }
 
         if !launched {
-            return Err("No terminal emulator found. Please install gnome-terminal, konsole, or xterm.".to_string());
+            return Err(
+                "No terminal emulator found. Install alacritty, kitty, wezterm, foot, \
+                 gnome-terminal, tilix, xfce4-terminal, konsole, or xterm, or set \
+                 NOORNOTE_TERMINAL / \"terminal\" in ~/.noornote/config."
+                    .to_string(),
+            );
         }
     }
 
     #[cfg(target_os = "windows")]
     {
-        Command::new("cmd")
-            .arg("/c")
-            .arg("start")
-            .arg(noorsigner_path.to_str().unwrap())
-            .arg(command)
+        let term_config = configured_term_config()
+            .or_else(default_term_config)
+            .ok_or_else(|| {
+                "No supported terminal found. Install Windows Terminal or PowerShell, or set \
+                 terminal_exec in ~/.noornote/config."
+                    .to_string()
+            })?;
+
+        Command::new(&term_config.exec)
+            .args(&term_config.args)
+            .arg(format!("{} {}", noorsigner_path.display(), command))
             .spawn()
-            .map_err(|e| format!("Failed to launch NoorSigner: {}", e))?;
+            .map_err(|e| format!("Failed to launch terminal: {}", e))?;
     }
 
-    println!("NoorSigner launched successfully");
+    log::info!("NoorSigner launched successfully");
 
     Ok(())
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chacha20poly1305::aead::{Aead, Payload};
+    use chacha20poly1305::{KeyInit, XChaCha20Poly1305, XNonce};
+
+    /// Seal a trust_session the same way NoorSigner does: an AEAD tag over
+    /// empty plaintext with `token|expires_unix|created_unix` as AAD.
+    fn seal_trust_session(token: &str, expires_unix: i64, created_unix: i64) -> (String, Vec<u8>) {
+        let salt = vec![9u8; 16];
+        let key = derive_trust_session_key(&salt).expect("key derivation should succeed in tests");
+        let nonce_bytes = [3u8; 24];
+        let canonical = format!("{}|{}|{}", token, expires_unix, created_unix);
+        let cipher = XChaCha20Poly1305::new_from_slice(&key).unwrap();
+        let tag = cipher
+            .encrypt(XNonce::from_slice(&nonce_bytes), Payload { msg: b"", aad: canonical.as_bytes() })
+            .expect("seal should succeed");
+        let content = format!(
+            "{}:{}:{}:{}:{}",
+            token,
+            expires_unix,
+            created_unix,
+            hex_encode(&nonce_bytes),
+            hex_encode(&tag)
+        );
+        (content, salt)
+    }
+
+    #[test]
+    fn hex_decode_round_trips() {
+        let bytes = vec![0x00, 0x7a, 0xff];
+        assert_eq!(hex_decode(&hex_encode(&bytes)).unwrap(), bytes);
+    }
+
+    #[test]
+    fn hex_decode_rejects_odd_length() {
+        assert!(hex_decode("abc").is_err());
+    }
+
+    #[test]
+    fn accepts_a_well_formed_session() {
+        let (content, salt) = seal_trust_session("tok-1", 9_999_999_999, 1_000_000_000);
+        assert!(verify_trust_session(&content, &salt, 1_700_000_000));
+    }
+
+    #[test]
+    fn rejects_an_expired_session() {
+        let (content, salt) = seal_trust_session("tok-1", 1_000_000_001, 1_000_000_000);
+        assert!(!verify_trust_session(&content, &salt, 1_700_000_000));
+    }
+
+    #[test]
+    fn rejects_a_tampered_token() {
+        let (content, salt) = seal_trust_session("tok-1", 9_999_999_999, 1_000_000_000);
+        let tampered = content.replacen("tok-1", "tok-2", 1);
+        assert!(!verify_trust_session(&tampered, &salt, 1_700_000_000));
+    }
+
+    #[test]
+    fn rejects_a_tampered_expiry() {
+        let (content, salt) = seal_trust_session("tok-1", 9_999_999_999, 1_000_000_000);
+        let tampered = content.replacen("9999999999", "9999999998", 1);
+        assert!(!verify_trust_session(&tampered, &salt, 1_700_000_000));
+    }
+
+    #[test]
+    fn rejects_malformed_hex_fields() {
+        let content = "tok:9999999999:1000000000:not-hex:also-not-hex".to_string();
+        assert!(!verify_trust_session(&content, &[0u8; 16], 1_700_000_000));
+    }
+
+    #[test]
+    fn rejects_wrong_nonce_length() {
+        let (content, salt) = seal_trust_session("tok-1", 9_999_999_999, 1_000_000_000);
+        let parts: Vec<&str> = content.split(':').collect();
+        let tampered = format!("{}:{}:{}:{}:{}", parts[0], parts[1], parts[2], "aa", parts[4]);
+        assert!(!verify_trust_session(&tampered, &salt, 1_700_000_000));
+    }
+
+    #[test]
+    fn rejects_wrong_salt() {
+        let (content, _salt) = seal_trust_session("tok-1", 9_999_999_999, 1_000_000_000);
+        assert!(!verify_trust_session(&content, &[1u8; 16], 1_700_000_000));
+    }
+}
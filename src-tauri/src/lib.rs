@@ -1,4 +1,6 @@
 mod key_signer;
+mod signer_integrity;
+mod signer_watcher;
 
 use tauri::{Emitter, RunEvent, WindowEvent};
 use tauri_plugin_global_shortcut::{Code, Modifiers, Shortcut, ShortcutState, GlobalShortcutExt};
@@ -6,6 +8,7 @@ use tauri_plugin_global_shortcut::{Code, Modifiers, Shortcut, ShortcutState, Glo
 #[cfg_attr(mobile, tauri::mobile_entry_point)]
 pub fn run() {
   tauri::Builder::default()
+    .manage(key_signer::DaemonState::default())
     .plugin(tauri_plugin_keyring::init())
     .plugin(tauri_plugin_dialog::init())
     .plugin(tauri_plugin_fs::init())
@@ -33,18 +36,43 @@ pub fn run() {
       key_signer::launch_key_signer,
       key_signer::check_trust_session,
       key_signer::cancel_key_signer_launch,
-      key_signer::ensure_noorsigner_installed
+      key_signer::ensure_noorsigner_installed,
+      key_signer::daemon_status,
+      key_signer::kill_daemon,
+      key_signer::wait_daemon,
+      key_signer::reset_signer_state,
+      key_signer::collect_signer_diagnostics,
+      key_signer::ping_key_signer,
+      signer_integrity::verify_noorsigner_integrity
     ])
     .setup(|app| {
       // Register global keyboard shortcuts
       register_global_shortcuts(app)?;
-      if cfg!(debug_assertions) {
-        app.handle().plugin(
-          tauri_plugin_log::Builder::default()
-            .level(log::LevelFilter::Info)
-            .build(),
-        )?;
 
+      // Route all logging (including KeySigner's IPC/launch/lifecycle tracing) through
+      // a rotating file under ~/.noornote/logs/ so field bug reports don't require
+      // reproducing the issue in a dev console.
+      let logs_dir = key_signer::get_noornote_base_path()
+        .map_err(Box::<dyn std::error::Error>::from)?
+        .join("logs");
+      app.handle().plugin(
+        tauri_plugin_log::Builder::default()
+          .target(tauri_plugin_log::Target::new(tauri_plugin_log::TargetKind::Folder {
+            path: logs_dir,
+            file_name: Some("noornote".to_string()),
+          }))
+          .max_file_size(10_000_000)
+          .rotation_strategy(tauri_plugin_log::RotationStrategy::KeepAll)
+          .level(log::LevelFilter::Info)
+          .build(),
+      )?;
+
+      // Watch the NoorSigner socket/pipe and trust session for changes
+      if let Err(e) = signer_watcher::start(app.handle().clone()) {
+        log::error!("Failed to start signer health watcher: {}", e);
+      }
+
+      if cfg!(debug_assertions) {
         use tauri::Manager;
         let window = app.get_webview_window("main").unwrap();
 
@@ -0,0 +1,228 @@
+/**
+ * NoorSigner binary integrity verification
+ * Checks the resolved NoorSigner binary against a signed manifest before launch
+ */
+use crate::key_signer::{hex_decode, hex_encode};
+use ed25519_dalek::{Signature, Verifier, VerifyingKey};
+use serde::Deserialize;
+use sha2::{Digest, Sha256};
+use std::path::{Path, PathBuf};
+use tauri::command;
+
+/// Hard-coded public key (hex) used to verify the NoorSigner release manifest.
+/// Corresponds to the NoorSigner release signing key; the matching private key
+/// lives with the release pipeline, never in this repo. See
+/// `scripts/sign_noorsigner_manifest.sh` for how a manifest is produced.
+const MANIFEST_PUBLIC_KEY_HEX: &str =
+    "7a0b412f112b8532647b24bfb3b0841016b85411c106a4c96b236c4c9b605f91";
+
+#[derive(Deserialize)]
+struct Manifest {
+    target: String,
+    sha256: String,
+    version: String,
+    signature: String,
+}
+
+/// Same set of candidate locations `get_sidecar_source_path` checks, since the
+/// manifest ships alongside the sidecar binary it describes.
+fn manifest_candidate_paths() -> Result<Vec<PathBuf>, String> {
+    let exe_path = std::env::current_exe()
+        .map_err(|e| format!("Failed to get current executable path: {}", e))?;
+    let exe_dir = exe_path
+        .parent()
+        .ok_or_else(|| "Failed to get executable directory".to_string())?;
+
+    Ok(vec![
+        exe_dir.join("noorsigner.manifest.json"),
+        PathBuf::from("/usr/lib/noornote").join("noorsigner.manifest.json"),
+        exe_dir.join("../Resources").join("noorsigner.manifest.json"),
+        exe_dir.join("../../binaries").join("noorsigner.manifest.json"),
+    ])
+}
+
+/// Load the bundled manifest, if one is present at any candidate location.
+/// Returns `Ok(None)` (not an error) when no manifest exists at all, since the
+/// release pipeline that produces one doesn't ship with every build yet -
+/// `verify_binary` treats that as "unverified", not as "tampered". A manifest
+/// file that exists but fails to read or parse is still a hard error.
+fn load_manifest() -> Result<Option<Manifest>, String> {
+    for path in manifest_candidate_paths()? {
+        if path.exists() {
+            let bytes = std::fs::read(&path)
+                .map_err(|e| format!("Failed to read manifest {:?}: {}", path, e))?;
+            let manifest = serde_json::from_slice(&bytes)
+                .map_err(|e| format!("Failed to parse manifest {:?}: {}", path, e))?;
+            return Ok(Some(manifest));
+        }
+    }
+    Ok(None)
+}
+
+fn current_target_triple() -> &'static str {
+    #[cfg(all(target_os = "linux", target_arch = "x86_64"))]
+    return "x86_64-unknown-linux-gnu";
+    #[cfg(all(target_os = "linux", target_arch = "aarch64"))]
+    return "aarch64-unknown-linux-gnu";
+    #[cfg(all(target_os = "macos", target_arch = "x86_64"))]
+    return "x86_64-apple-darwin";
+    #[cfg(all(target_os = "macos", target_arch = "aarch64"))]
+    return "aarch64-apple-darwin";
+    #[cfg(all(target_os = "windows", target_arch = "x86_64"))]
+    return "x86_64-pc-windows-msvc";
+}
+
+fn verify_manifest_signature(manifest: &Manifest) -> Result<(), String> {
+    let key_bytes = hex_decode(MANIFEST_PUBLIC_KEY_HEX).map_err(|_| "Invalid embedded public key".to_string())?;
+    let key_array: [u8; 32] = key_bytes
+        .try_into()
+        .map_err(|_| "Invalid embedded public key length".to_string())?;
+    verify_manifest_signature_with_key(manifest, &key_array)
+}
+
+/// Signature check against an explicit public key, so tests can exercise the
+/// accept/reject logic without touching the embedded release key.
+fn verify_manifest_signature_with_key(manifest: &Manifest, key_bytes: &[u8; 32]) -> Result<(), String> {
+    let verifying_key =
+        VerifyingKey::from_bytes(key_bytes).map_err(|e| format!("Invalid embedded public key: {}", e))?;
+
+    let sig_bytes = hex_decode(&manifest.signature).map_err(|_| "Malformed manifest signature".to_string())?;
+    let sig_array: [u8; 64] = sig_bytes
+        .try_into()
+        .map_err(|_| "Malformed manifest signature length".to_string())?;
+    let signature = Signature::from_bytes(&sig_array);
+
+    let canonical = format!("{}|{}|{}", manifest.target, manifest.sha256, manifest.version);
+    verifying_key
+        .verify(canonical.as_bytes(), &signature)
+        .map_err(|_| "Manifest signature verification failed".to_string())
+}
+
+fn sha256_hex(path: &Path) -> Result<String, String> {
+    let bytes = std::fs::read(path).map_err(|e| format!("Failed to read binary for hashing: {}", e))?;
+    let mut hasher = Sha256::new();
+    hasher.update(&bytes);
+    Ok(hex_encode(&hasher.finalize()))
+}
+
+/// Verify the resolved NoorSigner binary against the bundled, signed manifest.
+///
+/// If no manifest is present, verification is skipped and this returns
+/// `Ok(())` - the signed-manifest pipeline doesn't produce one for every build
+/// yet, and refusing to launch in that case would break every install. Once a
+/// manifest *is* present, it must validate: a bad signature, a target
+/// mismatch, or a digest mismatch all fail the launch.
+pub fn verify_binary(binary_path: &Path) -> Result<(), String> {
+    let Some(manifest) = load_manifest()? else {
+        log::warn!(
+            "No NoorSigner integrity manifest found at {:?}; skipping verification",
+            binary_path
+        );
+        return Ok(());
+    };
+    verify_manifest_signature(&manifest)?;
+
+    let expected_target = current_target_triple();
+    if manifest.target != expected_target {
+        return Err(format!(
+            "Manifest target '{}' does not match this platform ('{}')",
+            manifest.target, expected_target
+        ));
+    }
+
+    let actual_sha256 = sha256_hex(binary_path)?;
+    if !actual_sha256.eq_ignore_ascii_case(&manifest.sha256) {
+        return Err("NoorSigner binary failed integrity verification: digest mismatch".to_string());
+    }
+
+    Ok(())
+}
+
+/// Expose the integrity check to the UI. Reports whether a manifest was found
+/// at all, since "verified" and "nothing to verify against" are different
+/// states a user should be able to tell apart.
+#[command]
+pub async fn verify_noorsigner_integrity() -> Result<String, String> {
+    let binary_path = crate::key_signer::get_noorsigner_path()?;
+    match load_manifest()? {
+        Some(manifest) => {
+            verify_manifest_signature(&manifest)?;
+
+            let expected_target = current_target_triple();
+            if manifest.target != expected_target {
+                return Err(format!(
+                    "Manifest target '{}' does not match this platform ('{}')",
+                    manifest.target, expected_target
+                ));
+            }
+
+            let actual_sha256 = sha256_hex(&binary_path)?;
+            if !actual_sha256.eq_ignore_ascii_case(&manifest.sha256) {
+                return Err("NoorSigner binary failed integrity verification: digest mismatch".to_string());
+            }
+
+            Ok("NoorSigner binary verified".to_string())
+        }
+        None => Ok("No integrity manifest found - binary was not verified".to_string()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ed25519_dalek::{Signer, SigningKey};
+
+    /// Test-only keypair, fixed so tests are deterministic. Not the production
+    /// release key in `MANIFEST_PUBLIC_KEY_HEX`.
+    const TEST_SEED: [u8; 32] = [7u8; 32];
+
+    fn signed_manifest(target: &str, sha256: &str, version: &str) -> (Manifest, [u8; 32]) {
+        let signing_key = SigningKey::from_bytes(&TEST_SEED);
+        let canonical = format!("{}|{}|{}", target, sha256, version);
+        let signature = signing_key.sign(canonical.as_bytes());
+        let manifest = Manifest {
+            target: target.to_string(),
+            sha256: sha256.to_string(),
+            version: version.to_string(),
+            signature: hex_encode(&signature.to_bytes()),
+        };
+        (manifest, signing_key.verifying_key().to_bytes())
+    }
+
+    #[test]
+    fn accepts_a_well_formed_manifest() {
+        let (manifest, pubkey) = signed_manifest("x86_64-unknown-linux-gnu", &"a".repeat(64), "1.0.0");
+        assert!(verify_manifest_signature_with_key(&manifest, &pubkey).is_ok());
+    }
+
+    #[test]
+    fn rejects_a_tampered_digest() {
+        let (mut manifest, pubkey) = signed_manifest("x86_64-unknown-linux-gnu", &"a".repeat(64), "1.0.0");
+        manifest.sha256 = "b".repeat(64);
+        assert!(verify_manifest_signature_with_key(&manifest, &pubkey).is_err());
+    }
+
+    #[test]
+    fn rejects_a_tampered_version() {
+        let (mut manifest, pubkey) = signed_manifest("x86_64-unknown-linux-gnu", &"a".repeat(64), "1.0.0");
+        manifest.version = "9.9.9".to_string();
+        assert!(verify_manifest_signature_with_key(&manifest, &pubkey).is_err());
+    }
+
+    #[test]
+    fn rejects_a_signature_from_the_wrong_key() {
+        let (manifest, _) = signed_manifest("x86_64-unknown-linux-gnu", &"a".repeat(64), "1.0.0");
+        let other_key = SigningKey::from_bytes(&[8u8; 32]);
+        assert!(
+            verify_manifest_signature_with_key(&manifest, &other_key.verifying_key().to_bytes()).is_err()
+        );
+    }
+
+    #[test]
+    fn verify_binary_is_lenient_when_no_manifest_is_present() {
+        // No manifest fixture is installed next to the test binary, so
+        // `load_manifest` returns `Ok(None)` and verification should be
+        // skipped rather than treated as tampering.
+        assert!(verify_binary(Path::new("/nonexistent/noorsigner")).is_ok());
+    }
+}
@@ -0,0 +1,92 @@
+/**
+ * NoorSigner health watcher
+ * Watches the signer socket/pipe and trust-session file for changes and
+ * emits frontend events so the UI isn't limited to checking on demand.
+ */
+use std::sync::mpsc::{channel, RecvTimeoutError};
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+use notify::{Event, RecommendedWatcher, RecursiveMode, Watcher};
+use tauri::{AppHandle, Emitter};
+
+use crate::key_signer::{get_socket_path, get_trust_session_path};
+
+/// Start the background watcher. Registered from `run()`'s `.setup()`.
+pub fn start(app: AppHandle) -> notify::Result<()> {
+    let trust_session_path = get_trust_session_path()
+        .map_err(|e| notify::Error::generic(&e))?;
+    let watch_dir = trust_session_path
+        .parent()
+        .ok_or_else(|| notify::Error::generic("trust session path has no parent directory"))?
+        .to_path_buf();
+    std::fs::create_dir_all(&watch_dir).ok();
+
+    let (tx, rx) = channel();
+    let mut watcher: RecommendedWatcher =
+        notify::recommended_watcher(move |res: notify::Result<Event>| {
+            let _ = tx.send(res);
+        })?;
+    watcher.watch(&watch_dir, RecursiveMode::NonRecursive)?;
+
+    std::thread::spawn(move || {
+        // Keep the watcher alive for the lifetime of this thread
+        let _watcher = watcher;
+        let mut signer_connected = signer_present();
+
+        loop {
+            match rx.recv_timeout(Duration::from_millis(500)) {
+                Ok(Ok(_event)) => {}
+                Ok(Err(e)) => log::error!("Signer watcher error: {}", e),
+                Err(RecvTimeoutError::Timeout) => {}
+                Err(RecvTimeoutError::Disconnected) => break,
+            }
+
+            let now_connected = signer_present();
+            if now_connected != signer_connected {
+                signer_connected = now_connected;
+                let event = if signer_connected { "signer-connected" } else { "signer-disconnected" };
+                let _ = app.emit(event, ());
+            }
+
+            check_trust_session_expiry(&app);
+        }
+    });
+
+    Ok(())
+}
+
+#[cfg(unix)]
+fn signer_present() -> bool {
+    get_socket_path().map(|p| p.exists()).unwrap_or(false)
+}
+
+#[cfg(windows)]
+fn signer_present() -> bool {
+    use std::fs::OpenOptions;
+    get_socket_path()
+        .ok()
+        .map(|p| OpenOptions::new().read(true).write(true).open(p).is_ok())
+        .unwrap_or(false)
+}
+
+/// Remove an expired trust session file and tell the frontend it happened.
+fn check_trust_session_expiry(app: &AppHandle) {
+    let Ok(trust_session_path) = get_trust_session_path() else { return };
+    let Ok(content) = std::fs::read_to_string(&trust_session_path) else { return };
+
+    let parts: Vec<&str> = content.trim().split(':').collect();
+    if parts.len() < 2 {
+        return;
+    }
+    let Ok(expires_unix) = parts[1].parse::<i64>() else { return };
+
+    let now = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|d| d.as_secs() as i64)
+        .unwrap_or(0);
+
+    if now >= expires_unix {
+        let _ = std::fs::remove_file(&trust_session_path);
+        let _ = app.emit("trust-session-expired", ());
+    }
+}